@@ -0,0 +1,343 @@
+// Persistent, append-only BettiFrame log
+// Turns the orchestrator's ephemeral discovery results into a durable,
+// replayable history following the hypercore model: every appended frame is
+// hash-chained to its predecessor so the whole log is verifiable after the
+// fact.
+
+use crate::BettiFrame;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+const DEFAULT_CACHE_CAPACITY: usize = 64;
+
+// Genesis hash for the first entry in the chain.
+const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+// One newline-delimited record on disk. The chain binds each entry to its
+// predecessor by hashing `prev_hash || serialized frame`, so tampering with
+// any frame invalidates every hash that follows it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LogEntry {
+    index: u64,
+    prev_hash: String,
+    hash: String,
+    frame: BettiFrame,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FrameLogError {
+    #[error("frame log I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("frame log encoding error: {0}")]
+    Encoding(#[from] serde_json::Error),
+
+    #[error("hash chain broken at entry {index}: expected {expected}, found {found}")]
+    ChainBroken {
+        index: u64,
+        expected: String,
+        found: String,
+    },
+}
+
+// Bounded least-recently-used cache of materialized frames. Sparse loads only
+// touch the indices a caller actually asks for, so this keeps the working set
+// small even when the on-disk log is large.
+#[derive(Debug)]
+struct FrameCache {
+    capacity: usize,
+    frames: HashMap<u64, BettiFrame>,
+    order: VecDeque<u64>,
+}
+
+impl FrameCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            frames: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, index: u64) -> Option<BettiFrame> {
+        let frame = self.frames.get(&index).cloned()?;
+        self.touch(index);
+        Some(frame)
+    }
+
+    fn put(&mut self, index: u64, frame: BettiFrame) {
+        if self.frames.insert(index, frame).is_none() {
+            self.order.push_back(index);
+        } else {
+            self.touch(index);
+        }
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.frames.remove(&evicted);
+            }
+        }
+    }
+
+    fn touch(&mut self, index: u64) {
+        if let Some(pos) = self.order.iter().position(|&i| i == index) {
+            self.order.remove(pos);
+            self.order.push_back(index);
+        }
+    }
+}
+
+// Append-only, content-addressed log of [`BettiFrame`]s.
+//
+// The on-disk format is newline-delimited JSON — one [`LogEntry`] per line —
+// mirroring the crate's existing reliance on the `serde` derives for encoding.
+// Byte offsets are kept in memory (one per index) so `get` can seek straight
+// to an entry without rehydrating the whole file.
+#[derive(Debug)]
+pub struct FrameLog {
+    path: PathBuf,
+    offsets: Vec<u64>,
+    head_hash: String,
+    cache: FrameCache,
+}
+
+impl FrameLog {
+    // Open (or create) the log at `path`, parsing each existing entry once to
+    // recover its byte offset and the chain head; the parsed frames are not
+    // kept in memory or put in the cache.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, FrameLogError> {
+        Self::open_with_capacity(path, DEFAULT_CACHE_CAPACITY)
+    }
+
+    pub fn open_with_capacity<P: AsRef<Path>>(
+        path: P,
+        cache_capacity: usize,
+    ) -> Result<Self, FrameLogError> {
+        let path = path.as_ref().to_path_buf();
+        let mut offsets = Vec::new();
+        let mut head_hash = GENESIS.to_string();
+
+        if path.exists() {
+            let mut reader = BufReader::new(File::open(&path)?);
+            let mut pos = 0u64;
+            let mut line = String::new();
+            loop {
+                line.clear();
+                let read = reader.read_line(&mut line)?;
+                if read == 0 {
+                    break;
+                }
+                if !line.trim().is_empty() {
+                    let entry: LogEntry = serde_json::from_str(&line)?;
+                    head_hash = entry.hash;
+                    offsets.push(pos);
+                }
+                pos += read as u64;
+            }
+        }
+
+        Ok(Self {
+            path,
+            offsets,
+            head_hash,
+            cache: FrameCache::new(cache_capacity),
+        })
+    }
+
+    // Append `frame` to the log, hash-chaining it to the current head, and
+    // return its log index.
+    pub fn append(&mut self, frame: &BettiFrame) -> Result<u64, FrameLogError> {
+        let index = self.offsets.len() as u64;
+        let payload = serde_json::to_string(frame)?;
+        let hash = chain_hash(&self.head_hash, &payload);
+
+        let entry = LogEntry {
+            index,
+            prev_hash: self.head_hash.clone(),
+            hash: hash.clone(),
+            frame: frame.clone(),
+        };
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        // Record where this entry begins before writing it, so `get` can seek
+        // directly here later.
+        let offset = file.metadata()?.len();
+        writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+
+        self.head_hash = hash;
+        self.offsets.push(offset);
+        self.cache.put(index, frame.clone());
+        Ok(index)
+    }
+
+    // Load a single frame by index, sparsely — only the requested line is
+    // deserialized, and the result is kept in the LRU cache for reuse.
+    pub fn get(&mut self, index: u64) -> Option<BettiFrame> {
+        if let Some(frame) = self.cache.get(index) {
+            return Some(frame);
+        }
+        let entry = self.read_entry(index).ok().flatten()?;
+        self.cache.put(index, entry.frame.clone());
+        Some(entry.frame)
+    }
+
+    // Number of frames currently in the log.
+    pub fn len(&self) -> u64 {
+        self.offsets.len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    // Walk the hash chain from genesis and confirm no entry was tampered with.
+    pub fn verify(&self) -> Result<(), FrameLogError> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+        let reader = BufReader::new(File::open(&self.path)?);
+        let mut prev_hash = GENESIS.to_string();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: LogEntry = serde_json::from_str(&line)?;
+            if entry.prev_hash != prev_hash {
+                return Err(FrameLogError::ChainBroken {
+                    index: entry.index,
+                    expected: prev_hash,
+                    found: entry.prev_hash,
+                });
+            }
+            let payload = serde_json::to_string(&entry.frame)?;
+            let expected = chain_hash(&prev_hash, &payload);
+            if expected != entry.hash {
+                return Err(FrameLogError::ChainBroken {
+                    index: entry.index,
+                    expected,
+                    found: entry.hash,
+                });
+            }
+            prev_hash = entry.hash;
+        }
+        Ok(())
+    }
+
+    fn read_entry(&self, index: u64) -> Result<Option<LogEntry>, FrameLogError> {
+        let offset = match self.offsets.get(index as usize) {
+            Some(offset) => *offset,
+            None => return Ok(None),
+        };
+        let mut reader = BufReader::new(File::open(&self.path)?);
+        reader.seek(SeekFrom::Start(offset))?;
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let entry: LogEntry = serde_json::from_str(&line)?;
+        Ok(Some(entry))
+    }
+}
+
+// Hash-chain step: SHA-256 over the predecessor's hash concatenated with the
+// serialized frame, rendered as lowercase hex.
+fn chain_hash(prev_hash: &str, payload: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(prev_hash.as_bytes());
+    hasher.update(payload.as_bytes());
+    let digest = hasher.finalize();
+    let mut hex = String::with_capacity(digest.len() * 2);
+    for byte in digest {
+        hex.push_str(&format!("{:02x}", byte));
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Phase;
+    use std::io::Read as _;
+
+    fn frame(index: u32) -> BettiFrame {
+        BettiFrame {
+            equation: format!("E_{index}"),
+            betti_vector: [1, 0, 0],
+            chi: 1,
+            phase: Phase::Present,
+            phi_index: index,
+            timestamp: index as u64,
+            natural_flow: true,
+            dependencies: Vec::new(),
+            implications: Vec::new(),
+        }
+    }
+
+    fn temp_path() -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("frames.log");
+        (dir, path)
+    }
+
+    #[test]
+    fn append_then_sparse_get_roundtrips() {
+        let (_dir, path) = temp_path();
+        let mut log = FrameLog::open(&path).unwrap();
+
+        let i0 = log.append(&frame(0)).unwrap();
+        let i1 = log.append(&frame(1)).unwrap();
+        assert_eq!((i0, i1), (0, 1));
+
+        // Sparse load by index: the second frame comes back, not the first.
+        let got = log.get(1).unwrap();
+        assert_eq!(got.equation, "E_1");
+        assert_eq!(got.phi_index, 1);
+        assert!(log.get(2).is_none());
+    }
+
+    #[test]
+    fn reopen_recovers_len_and_chain_head() {
+        let (_dir, path) = temp_path();
+        {
+            let mut log = FrameLog::open(&path).unwrap();
+            log.append(&frame(0)).unwrap();
+            log.append(&frame(1)).unwrap();
+        }
+
+        // A fresh handle rebuilds its offset index and chain head from disk.
+        let mut log = FrameLog::open(&path).unwrap();
+        assert_eq!(log.len(), 2);
+        log.verify().unwrap();
+
+        // Appending after reopen continues the same chain.
+        let i2 = log.append(&frame(2)).unwrap();
+        assert_eq!(i2, 2);
+        log.verify().unwrap();
+        assert_eq!(log.get(0).unwrap().equation, "E_0");
+    }
+
+    #[test]
+    fn tampered_frame_breaks_verification() {
+        let (_dir, path) = temp_path();
+        {
+            let mut log = FrameLog::open(&path).unwrap();
+            log.append(&frame(0)).unwrap();
+            log.append(&frame(1)).unwrap();
+        }
+
+        // Corrupt the stored payload without fixing up the hash.
+        let mut contents = String::new();
+        File::open(&path).unwrap().read_to_string(&mut contents).unwrap();
+        let corrupted = contents.replace("E_0", "E_X");
+        assert_ne!(contents, corrupted);
+        std::fs::write(&path, corrupted).unwrap();
+
+        let log = FrameLog::open(&path).unwrap();
+        match log.verify() {
+            Err(FrameLogError::ChainBroken { index, .. }) => assert_eq!(index, 0),
+            other => panic!("expected ChainBroken, got {other:?}"),
+        }
+    }
+}
@@ -5,6 +5,17 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use async_trait::async_trait;
 
+pub mod frame_log;
+// `spawn`/`JoinHandle` round out the shim surface even though the crate's own
+// async paths currently only need `sleep`.
+#[allow(dead_code)]
+mod runtime;
+pub mod transport;
+#[cfg(feature = "proptest")]
+pub mod proptest_support;
+
+use frame_log::FrameLog;
+
 // Mathematical constants
 pub const PHI: f64 = 1.618033988749895;
 pub const PSI: f64 = 0.618033988749894;
@@ -31,6 +42,18 @@ pub enum Phase {
     Future,
 }
 
+impl Phase {
+    // φ-spaced position of a phase on the unit interval, used as the scalar
+    // input to phase-coherence measurements.
+    pub fn phase_value(self) -> f64 {
+        match self {
+            Phase::Past => 0.0,
+            Phase::Present => PSI,
+            Phase::Future => 1.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
     pub valid: bool,
@@ -51,7 +74,7 @@ pub trait MCPNode: Send + Sync {
     
     fn phase_lock(&self, other: &BettiFrame) -> f64 {
         // Measure phase coherence using φ-distance
-        let phase_diff = (self.get_phase_value() - other.get_phase_value()).abs();
+        let phase_diff = (self.get_phase_value() - other.phase.phase_value()).abs();
         if phase_diff < PSI {
             1.0 - (phase_diff / PSI)
         } else {
@@ -104,8 +127,36 @@ pub enum MCPError {
 use std::sync::{Arc, RwLock};
 use once_cell::sync::Lazy;
 
+#[cfg(feature = "bignum")]
+use num_bigint::BigUint;
+
 const FIBONACCI_BLOCK_SIZE: usize = 10;
 
+// Widened Fibonacci value type. The default `u128` path covers every index
+// up to F_186 before overflowing; the optional `bignum` feature swaps in an
+// arbitrary-precision integer so the computation never overflows.
+#[cfg(feature = "bignum")]
+pub type Fib = BigUint;
+#[cfg(not(feature = "bignum"))]
+pub type Fib = u128;
+
+#[cfg(feature = "bignum")]
+fn fib_zero() -> Fib {
+    BigUint::from(0u32)
+}
+#[cfg(feature = "bignum")]
+fn fib_one() -> Fib {
+    BigUint::from(1u32)
+}
+#[cfg(not(feature = "bignum"))]
+fn fib_zero() -> Fib {
+    0
+}
+#[cfg(not(feature = "bignum"))]
+fn fib_one() -> Fib {
+    1
+}
+
 // Global Fibonacci cache with block-based storage
 static FIBONACCI_CACHE: Lazy<Arc<RwLock<FibonacciCache>>> = Lazy::new(|| {
     Arc::new(RwLock::new(FibonacciCache::new()))
@@ -113,7 +164,10 @@ static FIBONACCI_CACHE: Lazy<Arc<RwLock<FibonacciCache>>> = Lazy::new(|| {
 
 #[derive(Debug)]
 pub struct FibonacciCache {
-    blocks: HashMap<usize, Vec<u64>>,
+    // Each block is a sparse Vec: `None` marks an index that has been padded
+    // for layout but not yet materialized, so a genuine F=0 is never confused
+    // with an absent value.
+    blocks: HashMap<usize, Vec<Option<Fib>>>,
     highest_computed: u32,
 }
 
@@ -124,7 +178,7 @@ impl FibonacciCache {
             highest_computed: 1,
         };
         // Initialize first block with base cases
-        cache.blocks.insert(0, vec![0, 1]);
+        cache.blocks.insert(0, vec![Some(fib_zero()), Some(fib_one())]);
         cache
     }
     
@@ -136,90 +190,53 @@ impl FibonacciCache {
         n as usize % FIBONACCI_BLOCK_SIZE
     }
     
-    pub fn compute(&mut self, n: u32) -> u64 {
-        if n <= 1 {
-            return n as u64;
-        }
-        
-        let block_idx = Self::get_block_index(n);
-        let block_offset = Self::get_block_offset(n);
-        
-        // Check if we already have this value
-        if let Some(block) = self.blocks.get(&block_idx) {
-            if block_offset < block.len() {
-                return block[block_offset];
-            }
-        }
-        
-        // Compute all values up to n using recursive approach with memoization
-        self.compute_up_to(n)
-    }
-    
-    fn compute_up_to(&mut self, n: u32) -> u64 {
-        // Ensure all previous blocks are computed
-        for i in (self.highest_computed + 1)..=n {
-            let fib_val = self.fibonacci_recursive(i);
-            self.store_value(i, fib_val);
-        }
-        
-        self.highest_computed = self.highest_computed.max(n);
-        self.get_value(n).unwrap()
-    }
-    
-    fn fibonacci_recursive(&mut self, n: u32) -> u64 {
-        if n <= 1 {
-            return n as u64;
-        }
-        
-        // Check cache first
+    pub fn compute(&mut self, n: u32) -> Fib {
+        // The block cache is now a memo of already-materialized indices: a
+        // hit short-circuits the O(log n) computation entirely.
         if let Some(val) = self.get_value(n) {
             return val;
         }
-        
-        // Recursive computation with memoization
-        let f1 = if let Some(val) = self.get_value(n - 1) {
-            val
-        } else {
-            self.fibonacci_recursive(n - 1)
-        };
-        
-        let f2 = if let Some(val) = self.get_value(n - 2) {
-            val
-        } else {
-            self.fibonacci_recursive(n - 2)
-        };
-        
-        f1 + f2
+
+        let value = fib_n_checked(n)
+            .expect("Fibonacci value overflowed u128; enable the `bignum` feature or use checked_fibonacci");
+        self.store_value(n, value);
+        self.highest_computed = self.highest_computed.max(n);
+        self.get_value(n).unwrap()
     }
-    
-    fn get_value(&self, n: u32) -> Option<u64> {
+
+    fn get_value(&self, n: u32) -> Option<Fib> {
         let block_idx = Self::get_block_index(n);
         let block_offset = Self::get_block_offset(n);
-        
+
         self.blocks.get(&block_idx)
             .and_then(|block| block.get(block_offset))
-            .copied()
+            .cloned()
+            .flatten()
     }
-    
-    fn store_value(&mut self, n: u32, value: u64) {
+
+    fn store_value(&mut self, n: u32, value: Fib) {
         let block_idx = Self::get_block_index(n);
         let block_offset = Self::get_block_offset(n);
-        
-        let block = self.blocks.entry(block_idx).or_insert_with(Vec::new);
-        
-        // Ensure block has enough capacity
+
+        let block = self.blocks.entry(block_idx).or_default();
+
+        // Ensure block has enough capacity, padding unmaterialized slots.
         while block.len() <= block_offset {
-            block.push(0);
+            block.push(None);
         }
-        
-        block[block_offset] = value;
+
+        block[block_offset] = Some(value);
     }
     
     pub fn get_statistics(&self) -> FibonacciCacheStats {
         FibonacciCacheStats {
             blocks_loaded: self.blocks.len(),
             highest_computed: self.highest_computed,
-            total_values: self.blocks.values().map(|b| b.len()).sum(),
+            total_values: self
+                .blocks
+                .values()
+                .map(|b| b.iter().filter(|v| v.is_some()).count())
+                .sum(),
         }
     }
 }
@@ -231,12 +248,89 @@ pub struct FibonacciCacheStats {
     pub total_values: usize,
 }
 
+// Fast-doubling Fibonacci: computes F(n) and F(n+1) together in a single
+// recursion over the bits of n, giving O(log n) multiplications instead of
+// the old linear recurrence. Given (F(k), F(k+1)):
+//   c = F(k)·(2·F(k+1) − F(k)) = F(2k)
+//   d = F(k)² + F(k+1)²        = F(2k+1)
+// a zero bit yields (c, d); a one bit yields (d, c + d).
+#[cfg(feature = "bignum")]
+fn fast_doubling(n: u32) -> (Fib, Fib) {
+    if n == 0 {
+        return (fib_zero(), fib_one());
+    }
+    let (a, b) = fast_doubling(n >> 1);
+    let c = &a * (&(&b + &b) - &a);
+    let d = &a * &a + &b * &b;
+    if n & 1 == 0 {
+        (c, d)
+    } else {
+        let cd = &c + &d;
+        (d, cd)
+    }
+}
+
+// Compute only F(n). The half-index pair (F(k), F(k+1)) is folded down to a
+// single value so the top level never materializes F(n+1) — that keeps the
+// usable u128 range up to F_186, the largest Fibonacci number that fits.
+#[cfg(feature = "bignum")]
+fn fib_n_checked(n: u32) -> Option<Fib> {
+    if n <= 1 {
+        return Some(BigUint::from(n));
+    }
+    let (a, b) = fast_doubling(n >> 1);
+    if n & 1 == 0 {
+        Some(&a * (&(&b + &b) - &a))
+    } else {
+        Some(&a * &a + &b * &b)
+    }
+}
+
+#[cfg(not(feature = "bignum"))]
+fn fast_doubling_checked(n: u32) -> Option<(Fib, Fib)> {
+    if n == 0 {
+        return Some((0, 1));
+    }
+    let (a, b) = fast_doubling_checked(n >> 1)?;
+    let c = a.checked_mul(b.checked_mul(2)?.checked_sub(a)?)?;
+    let d = a.checked_mul(a)?.checked_add(b.checked_mul(b)?)?;
+    if n & 1 == 0 {
+        Some((c, d))
+    } else {
+        Some((d, c.checked_add(d)?))
+    }
+}
+
+// Compute only F(n) on the u128 path. Folding the half-index pair down to a
+// single value means the top level never forms F(n+1), so F_186 — the largest
+// Fibonacci number below u128::MAX — is reachable; F_187 overflows and yields
+// `None`.
+#[cfg(not(feature = "bignum"))]
+fn fib_n_checked(n: u32) -> Option<Fib> {
+    if n <= 1 {
+        return Some(n as u128);
+    }
+    let (a, b) = fast_doubling_checked(n >> 1)?;
+    if n & 1 == 0 {
+        a.checked_mul(b.checked_mul(2)?.checked_sub(a)?)
+    } else {
+        a.checked_mul(a)?.checked_add(b.checked_mul(b)?)
+    }
+}
+
 // Public interface with thread-safe access
-pub fn fibonacci(n: u32) -> u64 {
+pub fn fibonacci(n: u32) -> Fib {
     let mut cache = FIBONACCI_CACHE.write().unwrap();
     cache.compute(n)
 }
 
+// Overflow-safe variant: on the `u128` path this returns `None` once F(n)
+// exceeds `u128::MAX` (i.e. from F_187 on); with the `bignum` feature it is
+// always `Some`.
+pub fn checked_fibonacci(n: u32) -> Option<Fib> {
+    fib_n_checked(n)
+}
+
 pub fn fibonacci_stats() -> FibonacciCacheStats {
     let cache = FIBONACCI_CACHE.read().unwrap();
     cache.get_statistics()
@@ -246,7 +340,7 @@ pub fn fibonacci_stats() -> FibonacciCacheStats {
 pub fn compute_betti_vector(equation: &str) -> [u32; 3] {
     // Simplified Betti computation based on equation structure
     let components = equation.matches('=').count() as u32 + 1;
-    let cycles = equation.matches(|c: char| c == '(' || c == ')').count() as u32 / 2;
+    let cycles = equation.matches(['(', ')']).count() as u32 / 2;
     let voids = equation.matches("∫").count() as u32;
     
     [components, cycles, voids]
@@ -257,6 +351,9 @@ pub struct DiscoveryOrchestrator {
     nodes: HashMap<String, Box<dyn MCPNode>>,
     topology: BettiTopology,
     bushido_state: BushidoState,
+    // When present, every discovered frame is persisted to the append-only
+    // log so the run can be replayed and audited later.
+    frame_log: Option<FrameLog>,
 }
 
 #[derive(Debug, Clone)]
@@ -281,6 +378,11 @@ impl DiscoveryOrchestrator {
             match node.process(frame).await {
                 Ok(result) => {
                     if self.validate_discovery(&result) {
+                        if let Some(log) = self.frame_log.as_mut() {
+                            if let Err(e) = log.append(&result) {
+                                tracing::warn!("Frame log append failed: {}", e);
+                            }
+                        }
                         discoveries.push(result);
                     }
                 }
@@ -298,7 +400,7 @@ impl DiscoveryOrchestrator {
     
     async fn enter_void_state(&mut self) {
         // F₃ seconds of silence
-        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+        runtime::sleep(std::time::Duration::from_secs(2)).await;
         self.bushido_state.stream = Stream::Emptiness;
     }
     
@@ -337,7 +439,12 @@ impl DiscoveryOrchestrator {
 }
 
 // Hawking radiation style logging
-pub fn log_discovery(frame: &BettiFrame) {
+pub fn log_discovery(frame: &BettiFrame, log: Option<&mut FrameLog>) {
+    if let Some(log) = log {
+        if let Err(e) = log.append(frame) {
+            tracing::warn!("Frame log append failed: {}", e);
+        }
+    }
     println!("EQUATION φ.{}: {}", frame.phi_index, frame.equation);
     println!("TOPOLOGY: B=[{},{},{}] χ={}", 
         frame.betti_vector[0], frame.betti_vector[1], frame.betti_vector[2], frame.chi);
@@ -355,24 +462,70 @@ mod tests {
     
     #[test]
     fn test_fibonacci() {
-        assert_eq!(fibonacci(0), 0);
-        assert_eq!(fibonacci(1), 1);
-        assert_eq!(fibonacci(10), 55);
-        assert_eq!(fibonacci(17), 1597);
+        // Compare via Display so the same test covers both the `u128` and the
+        // `bignum` value type.
+        assert_eq!(fibonacci(0).to_string(), "0");
+        assert_eq!(fibonacci(1).to_string(), "1");
+        assert_eq!(fibonacci(10).to_string(), "55");
+        assert_eq!(fibonacci(17).to_string(), "1597");
+    }
+
+    #[test]
+    fn test_fibonacci_fast_doubling_beyond_u64() {
+        // F_93 overflows u64 in the old recursive path; fast doubling over
+        // the widened type handles it exactly.
+        assert_eq!(fibonacci(93).to_string(), "12200160415121876738");
+        assert_eq!(fibonacci(100).to_string(), "354224848179261915075");
+    }
+
+    #[cfg(not(feature = "bignum"))]
+    #[test]
+    fn test_checked_fibonacci_overflow() {
+        // F_186 is the last index that fits in u128.
+        assert!(checked_fibonacci(186).is_some());
+        assert_eq!(checked_fibonacci(187), None);
     }
     
     #[test]
     fn test_phase_lock() {
-        let frame1 = BettiFrame {
-            phase: Phase::Past,
-            ..Default::default()
+        // A node parked in the Present phase locking against frames in each
+        // phase: identical phase is perfectly coherent, a φ-distant one is not.
+        let node = PhaseNode {
+            value: Phase::Present.phase_value(),
         };
-        let frame2 = BettiFrame {
-            phase: Phase::Present,
-            ..Default::default()
-        };
-        
-        // Test phase coherence calculation
-        // Implementation depends on specific node types
+        let present = frame_in(Phase::Present);
+        let future = frame_in(Phase::Future);
+
+        assert_eq!(node.phase_lock(&present), 1.0);
+        assert!(node.phase_lock(&future) < 1.0);
+    }
+
+    struct PhaseNode {
+        value: f64,
+    }
+
+    #[async_trait]
+    impl MCPNode for PhaseNode {
+        async fn process(&self, frame: BettiFrame) -> Result<BettiFrame, MCPError> {
+            Ok(frame)
+        }
+
+        fn get_phase_value(&self) -> f64 {
+            self.value
+        }
+    }
+
+    fn frame_in(phase: Phase) -> BettiFrame {
+        BettiFrame {
+            equation: String::new(),
+            betti_vector: [1, 0, 0],
+            chi: 1,
+            phase,
+            phi_index: 1,
+            timestamp: 0,
+            natural_flow: true,
+            dependencies: Vec::new(),
+            implications: Vec::new(),
+        }
     }
 }
\ No newline at end of file
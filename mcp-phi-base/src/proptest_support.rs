@@ -0,0 +1,230 @@
+// Property-based verification of topological invariants
+// A `proptest`-driven harness that generates random frames and topology
+// states and asserts the crate's core mathematical contracts. The strategies
+// are part of the public (feature-gated) API so downstream node implementors
+// can fuzz their own `process` implementations against the same invariants.
+
+use crate::{BettiFrame, BettiTopology, Phase};
+use proptest::arbitrary::Arbitrary;
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+// Strategy over the three discovery phases.
+pub fn phase_strategy() -> impl Strategy<Value = Phase> {
+    prop_oneof![
+        Just(Phase::Past),
+        Just(Phase::Present),
+        Just(Phase::Future),
+    ]
+}
+
+// Strategy over arbitrary [`BettiFrame`]s. `chi` is drawn independently of the
+// Betti vector so that generated frames exercise both the topology-consistent
+// and the inconsistent case.
+pub fn betti_frame_strategy() -> impl Strategy<Value = BettiFrame> {
+    (
+        any::<String>(),
+        [0u32..50, 0u32..50, 0u32..50],
+        -150i32..150,
+        phase_strategy(),
+        0u32..200,
+        any::<u64>(),
+        any::<bool>(),
+        prop::collection::vec(any::<String>(), 0..4),
+        prop::collection::vec(any::<String>(), 0..4),
+    )
+        .prop_map(
+            |(
+                equation,
+                betti_vector,
+                chi,
+                phase,
+                phi_index,
+                timestamp,
+                natural_flow,
+                dependencies,
+                implications,
+            )| BettiFrame {
+                equation,
+                betti_vector,
+                chi,
+                phase,
+                phi_index,
+                timestamp,
+                natural_flow,
+                dependencies,
+                implications,
+            },
+        )
+}
+
+// Strategy over arbitrary [`BettiTopology`] states, with `target_chi` drawn
+// independently of the Betti numbers.
+pub fn betti_topology_strategy() -> impl Strategy<Value = BettiTopology> {
+    (0u32..50, 0u32..50, 0u32..50, -150i32..150).prop_map(|(b0, b1, b2, target_chi)| {
+        BettiTopology {
+            b0,
+            b1,
+            b2,
+            target_chi,
+        }
+    })
+}
+
+// `Arbitrary` impls so downstream `proptest!` blocks can write
+// `any::<BettiFrame>()` / `any::<Phase>()` / `any::<BettiTopology>()` directly;
+// each delegates to the reusable strategy above.
+impl Arbitrary for Phase {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        phase_strategy().boxed()
+    }
+}
+
+impl Arbitrary for BettiFrame {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        betti_frame_strategy().boxed()
+    }
+}
+
+impl Arbitrary for BettiTopology {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        betti_topology_strategy().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BushidoState, DiscoveryOrchestrator, MCPError, MCPNode, Phase, Stream,
+    };
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+
+    // Minimal node used purely to exercise the trait's default methods.
+    struct InvariantNode {
+        phase_value: f64,
+    }
+
+    #[async_trait]
+    impl MCPNode for InvariantNode {
+        async fn process(&self, frame: BettiFrame) -> Result<BettiFrame, MCPError> {
+            Ok(frame)
+        }
+
+        fn get_phase_value(&self) -> f64 {
+            self.phase_value
+        }
+    }
+
+    fn orchestrator_with(topology: BettiTopology) -> DiscoveryOrchestrator {
+        DiscoveryOrchestrator {
+            nodes: HashMap::new(),
+            topology,
+            bushido_state: BushidoState {
+                stream: Stream::Emptiness,
+                honor_level: 0.0,
+                focus_depth: 0,
+                flow_state: false,
+                integration_count: 0,
+            },
+            frame_log: None,
+        }
+    }
+
+    // Build a frame carrying `phase`; other fields are irrelevant to
+    // phase-lock and left at trivial defaults.
+    fn frame_with_phase(phase: Phase) -> BettiFrame {
+        BettiFrame {
+            equation: String::new(),
+            betti_vector: [1, 0, 0],
+            chi: 1,
+            phase,
+            phi_index: 1,
+            timestamp: 0,
+            natural_flow: true,
+            dependencies: Vec::new(),
+            implications: Vec::new(),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn validate_topology_agrees_with_euler_formula(frame in betti_frame_strategy()) {
+            let node = InvariantNode { phase_value: 0.0 };
+            let expected = frame.chi
+                == frame.betti_vector[0] as i32 - frame.betti_vector[1] as i32
+                    + frame.betti_vector[2] as i32;
+            prop_assert_eq!(node.validate_topology(&frame), expected);
+        }
+
+        #[test]
+        fn phase_lock_in_unit_interval_and_symmetric(
+            pa in phase_strategy(),
+            pb in phase_strategy(),
+        ) {
+            // Exercise the real trait method: a node stamped with phase `pa`
+            // locking against a frame carrying phase `pb`, and the mirror.
+            let node_a = InvariantNode { phase_value: pa.phase_value() };
+            let node_b = InvariantNode { phase_value: pb.phase_value() };
+            let ab = node_a.phase_lock(&frame_with_phase(pb));
+            let ba = node_b.phase_lock(&frame_with_phase(pa));
+            prop_assert!((0.0..=1.0).contains(&ab));
+            prop_assert!((ab - ba).abs() < 1e-12);
+        }
+
+        #[test]
+        fn ensure_euler_characteristic_fails_exactly_on_divergence(
+            topology in betti_topology_strategy()
+        ) {
+            let current =
+                topology.b0 as i32 - topology.b1 as i32 + topology.b2 as i32;
+            let diverges = current != topology.target_chi;
+            let orchestrator = orchestrator_with(topology);
+            prop_assert_eq!(orchestrator.ensure_euler_characteristic().is_err(), diverges);
+        }
+
+        // Drive the frame_log subsystem through the `Arbitrary` impl: a run of
+        // generated frames appends, verifies, and sparse-loads back unchanged.
+        #[test]
+        fn frame_log_roundtrips_generated_frames(
+            frames in prop::collection::vec(any::<BettiFrame>(), 1..6)
+        ) {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("frames.log");
+            let mut log = crate::frame_log::FrameLog::open(&path).unwrap();
+
+            for frame in &frames {
+                log.append(frame).unwrap();
+            }
+
+            prop_assert_eq!(log.len(), frames.len() as u64);
+            prop_assert!(log.verify().is_ok());
+
+            for (i, frame) in frames.iter().enumerate() {
+                let got = log.get(i as u64).unwrap();
+                prop_assert_eq!(
+                    serde_json::to_string(&got).unwrap(),
+                    serde_json::to_string(frame).unwrap(),
+                );
+            }
+        }
+    }
+
+    // Keep `Phase`'s strategy referenced outside the generated tests so the
+    // public export is exercised.
+    #[test]
+    fn phase_strategy_is_public() {
+        let _ = phase_strategy();
+        let _ = Phase::Present;
+    }
+}
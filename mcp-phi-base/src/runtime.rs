@@ -0,0 +1,104 @@
+// Runtime shim
+// Keeps the crate from forcing a particular executor on its consumers. The
+// `tokio` feature (default) and the `async-std` feature each select a timer
+// and task spawner behind the same small surface, so discovery code never
+// names a runtime directly.
+
+use std::future::Future;
+use std::time::Duration;
+
+#[cfg(all(feature = "tokio", feature = "async-std"))]
+compile_error!("enable exactly one of the `tokio` or `async-std` features");
+
+#[cfg(feature = "tokio")]
+pub type JoinHandle<T> = tokio::task::JoinHandle<T>;
+
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub type JoinHandle<T> = async_std::task::JoinHandle<T>;
+
+// Suspend the current task for `duration` using the selected runtime's timer.
+#[cfg(feature = "tokio")]
+pub async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub async fn sleep(duration: Duration) {
+    async_std::task::sleep(duration).await;
+}
+
+// Spawn `future` onto the selected runtime, returning its join handle.
+#[cfg(feature = "tokio")]
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::task::spawn(future)
+}
+
+#[cfg(all(feature = "async-std", not(feature = "tokio")))]
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    async_std::task::spawn(future)
+}
+
+// Runtime-free fallback, selected with `--no-default-features` and no runtime
+// feature, rather than failing to compile, so the crate is still usable (e.g.
+// in tests) without pulling in tokio or async-std. `sleep` blocks the calling
+// thread; `spawn` drives the future on a new OS thread with a minimal
+// executor.
+#[cfg(not(any(feature = "tokio", feature = "async-std")))]
+pub type JoinHandle<T> = std::thread::JoinHandle<T>;
+
+#[cfg(not(any(feature = "tokio", feature = "async-std")))]
+pub async fn sleep(duration: Duration) {
+    std::thread::sleep(duration);
+}
+
+#[cfg(not(any(feature = "tokio", feature = "async-std")))]
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    std::thread::spawn(move || fallback::block_on(future))
+}
+
+#[cfg(not(any(feature = "tokio", feature = "async-std")))]
+mod fallback {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    // Drive a future to completion on the current thread, parking between
+    // polls until a wake unparks us.
+    pub fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker: Waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+}
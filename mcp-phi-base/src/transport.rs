@@ -0,0 +1,201 @@
+// Networked MCP transport
+// Lets discovery nodes exchange `BettiFrame`s across a network instead of
+// purely in-process. Split into two client traits in the style of a
+// transaction client library: an async fire-and-return path and a
+// synchronous-feeling confirm path that resubmits — like a blockchain client
+// refreshing its view and resending — until the remote phase-locks.
+
+use crate::runtime;
+use crate::{fibonacci, BettiFrame, MCPError, ValidationResult};
+use async_trait::async_trait;
+use std::time::Duration;
+
+// Upper bounds so a misconfigured `confirm_attempts_index` can't spin billions
+// of retries or sleep for hours between them. F_12 = 144 attempts, capped at
+// F_9 = 34 s per back-off.
+const MAX_CONFIRM_ATTEMPTS_INDEX: u32 = 12;
+const MAX_BACKOFF_INDEX: u32 = 9;
+
+// Fire-and-return: hand the frame to the remote and surface whatever
+// [`ValidationResult`] comes back, without waiting for phase coherence.
+#[async_trait]
+pub trait AsyncMCPClient: Send + Sync {
+    async fn send_frame(&self, frame: BettiFrame) -> Result<ValidationResult, MCPError>;
+}
+
+// Confirm path: resubmit until the remote returns a result whose `phase_lock`
+// clears the configured coherence threshold. Modelled on a blockchain client
+// resubmitting a transaction with a refreshed view — each retry re-stamps the
+// frame's `timestamp` and advances its `phi_index` before resending, backs off
+// on a phase-lock failure, and gives up with [`MCPError::DiscoveryTimeout`]
+// after F_n attempts.
+#[async_trait]
+pub trait SyncMCPClient: AsyncMCPClient {
+    // Minimum phase coherence the remote must report for a frame to confirm.
+    fn coherence_threshold(&self) -> f64;
+
+    // Fibonacci index bounding the retry budget: confirmation is attempted
+    // F_n times before timing out, with n capped at 12 (at most 144 attempts).
+    fn confirm_attempts_index(&self) -> u32;
+
+    async fn send_and_confirm_frame(&self, frame: BettiFrame) -> Result<BettiFrame, MCPError> {
+        let attempts = confirm_attempt_count(self.confirm_attempts_index());
+        let threshold = self.coherence_threshold();
+        let mut frame = frame;
+
+        for attempt in 0..attempts {
+            // Refresh the frame's view before each resubmission: re-stamp the
+            // timestamp and advance the φ-index.
+            if attempt > 0 {
+                frame.timestamp = now();
+                frame.phi_index = frame.phi_index.saturating_add(1);
+            }
+
+            match self.send_frame(frame.clone()).await {
+                Ok(result) if result.phase_lock >= threshold => return Ok(frame),
+                Ok(_) | Err(MCPError::PhaseLockFailure(_)) => {
+                    // Don't sleep after the final attempt — the timeout is
+                    // already guaranteed.
+                    if attempt + 1 < attempts {
+                        back_off(attempt).await;
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(MCPError::DiscoveryTimeout(self.confirm_attempts_index()))
+    }
+}
+
+// Marker tying both modes together for node implementations that support them.
+pub trait Client: SyncMCPClient + AsyncMCPClient {}
+
+impl<T: SyncMCPClient + AsyncMCPClient> Client for T {}
+
+// Clamped retry budget: F_index attempts, never more than F_12 = 144.
+fn confirm_attempt_count(index: u32) -> u32 {
+    u32::try_from(fibonacci(index.min(MAX_CONFIRM_ATTEMPTS_INDEX))).unwrap_or(u32::MAX)
+}
+
+// Clamped Fibonacci back-off, in seconds, for a given attempt — never more
+// than F_9 = 34 s.
+fn backoff_secs(attempt: u32) -> u64 {
+    u64::try_from(fibonacci(attempt.min(MAX_BACKOFF_INDEX))).unwrap_or(u64::MAX)
+}
+
+// Fibonacci back-off: each retry waits F_attempt seconds (capped), matching
+// the Flow stream's rhythm.
+async fn back_off(attempt: u32) {
+    runtime::sleep(Duration::from_secs(backoff_secs(attempt))).await;
+}
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "tokio")]
+    use crate::Phase;
+    #[cfg(feature = "tokio")]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Remote that counts calls and reports a fixed phase lock, so a test can
+    // drive the confirm/timeout paths deterministically. Only the async tests
+    // (gated on a runtime) use it.
+    #[cfg(feature = "tokio")]
+    struct MockClient {
+        threshold: f64,
+        attempts_index: u32,
+        phase_lock: f64,
+        calls: AtomicUsize,
+    }
+
+    #[cfg(feature = "tokio")]
+    #[async_trait]
+    impl AsyncMCPClient for MockClient {
+        async fn send_frame(&self, _frame: BettiFrame) -> Result<ValidationResult, MCPError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(ValidationResult {
+                valid: true,
+                confidence: 1.0,
+                method: "mock".to_string(),
+                phase_lock: self.phase_lock,
+            })
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[async_trait]
+    impl SyncMCPClient for MockClient {
+        fn coherence_threshold(&self) -> f64 {
+            self.threshold
+        }
+
+        fn confirm_attempts_index(&self) -> u32 {
+            self.attempts_index
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    fn frame() -> BettiFrame {
+        BettiFrame {
+            equation: String::new(),
+            betti_vector: [1, 0, 0],
+            chi: 1,
+            phase: Phase::Present,
+            phi_index: 1,
+            timestamp: 0,
+            natural_flow: true,
+            dependencies: Vec::new(),
+            implications: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn clamps_bound_attempts_and_backoff() {
+        // A huge configured index collapses to the F_12 ceiling.
+        let capped = u32::try_from(fibonacci(MAX_CONFIRM_ATTEMPTS_INDEX)).unwrap();
+        assert_eq!(confirm_attempt_count(100), capped);
+        assert_eq!(confirm_attempt_count(4), 3); // F_4 = 3, below the cap
+
+        // Back-off saturates at F_9 = 34 s.
+        assert_eq!(backoff_secs(100), backoff_secs(MAX_BACKOFF_INDEX));
+        assert_eq!(backoff_secs(3), 2); // F_3 = 2, below the cap
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn confirms_when_phase_lock_clears_threshold() {
+        let client = MockClient {
+            threshold: 0.5,
+            attempts_index: 4,
+            phase_lock: 0.9,
+            calls: AtomicUsize::new(0),
+        };
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let result = rt.block_on(client.send_and_confirm_frame(frame()));
+        assert!(result.is_ok());
+        assert_eq!(client.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn times_out_after_fibonacci_attempts() {
+        let client = MockClient {
+            threshold: 0.9,
+            attempts_index: 4, // F_4 = 3 attempts
+            phase_lock: 0.0,   // never clears the threshold
+            calls: AtomicUsize::new(0),
+        };
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let result = rt.block_on(client.send_and_confirm_frame(frame()));
+        assert!(matches!(result, Err(MCPError::DiscoveryTimeout(4))));
+        assert_eq!(client.calls.load(Ordering::SeqCst), 3);
+    }
+}